@@ -229,6 +229,577 @@ pub fn zoom_out(x: u32, y: u32) -> (u32, u32) {
     ( x / 2, y / 2)
 }
 
+/// Returns the geographic bounding box of a tile, at a given zoom.
+///
+/// # Arguments
+///
+/// * `x`  - X tile coordinate
+/// * `y`  - Y tile coordinate
+/// * `zoom` - zoomlevel of the tile
+///
+/// # Output
+///
+/// The bounding box is returned as `(west, south, east, north)`, in degrees.
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::tile_bbox(4376, 2932, 13);
+///     println!("Tile (4376, 2932) at zoom 13: {:?}", res);
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function is built directly on `tile2lonlat`, and therefore **does not** check on the
+/// *validity* of the data in input; passing non valid tiles for the relative zoomlevel still
+/// gets in output a result, albeit *meaningless*.
+pub fn tile_bbox(x: u32, y: u32, zoom: u8) -> (f64, f64, f64, f64) {
+    let nw = tile2lonlat(x, y, zoom);
+    let se = tile2lonlat(x + 1, y + 1, zoom);
+    (nw.0, se.1, se.0, nw.1)
+}
+
+/// Convert a tile to a quadkey, as used by Bing/Azure-style tile addressing.
+///
+/// # Arguments
+///
+/// * `x`  - X tile coordinate
+/// * `y`  - Y tile coordinate
+/// * `zoom` - zoomlevel of the tile
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::tile2quadkey(4376, 2932, 13);
+///     println!("Tile (4376, 2932) at zoom 13: {}", res);
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function **does not** check on the *validity* of the data in input;
+/// passing non valid tiles for the relative zoomlevel still gets in output a result, albeit *meaningless*.
+pub fn tile2quadkey(x: u32, y: u32, zoom: u8) -> String {
+    let mut quadkey = String::with_capacity(zoom as usize);
+    for i in (1..=zoom).rev() {
+        let mask = 1u32 << (i - 1);
+        let mut digit = 0u8;
+        if x & mask != 0 {
+            digit += 1;
+        }
+        if y & mask != 0 {
+            digit += 2;
+        }
+        quadkey.push((b'0' + digit) as char);
+    }
+    quadkey
+}
+
+/// Convert a quadkey back to a tile, as used by Bing/Azure-style tile addressing.
+///
+/// # Arguments
+///
+/// * `quadkey` - the quadkey string
+///
+/// # Output
+///
+/// The tile is returned as `(x, y, zoom)`, where `zoom` is the length of the quadkey.
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::quadkey2tile("1202102332220");
+///     println!("{:?}", res);
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function **does not** check on the *validity* of the data in input;
+/// passing a quadkey containing characters other than `'0'..'3'` still gets in output a result,
+/// albeit *meaningless*.
+pub fn quadkey2tile(quadkey: &str) -> (u32, u32, u8) {
+    let zoom = quadkey.len() as u8;
+    let mut x: u32 = 0;
+    let mut y: u32 = 0;
+    for (i, c) in quadkey.chars().enumerate() {
+        let mask = 1u32 << (zoom as usize - i - 1);
+        let digit = (c as u8).wrapping_sub(b'0');
+        if digit & 1 != 0 {
+            x |= mask;
+        }
+        if digit & 2 != 0 {
+            y |= mask;
+        }
+    }
+    (x, y, zoom)
+}
+
+/// An iterator over all the tiles covering a lon/lat bounding box, at a given zoom.
+///
+/// Built by `bbox_tiles`; iterates row by row, from the north-west tile to the south-east tile.
+pub struct BboxTiles {
+    x_min: u32,
+    x_max: u32,
+    y_max: u32,
+    x: u32,
+    y: u32,
+    done: bool,
+}
+
+impl Iterator for BboxTiles {
+    type Item = (u32, u32);
+
+    fn next(&mut self) -> Option<(u32, u32)> {
+        if self.done {
+            return None;
+        }
+        let current = (self.x, self.y);
+        if self.x == self.x_max {
+            if self.y == self.y_max {
+                self.done = true;
+            } else {
+                self.x = self.x_min;
+                self.y += 1;
+            }
+        } else {
+            self.x += 1;
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BboxTiles {
+    fn len(&self) -> usize {
+        if self.done {
+            return 0;
+        }
+        let cols = (self.x_max - self.x + 1) as usize;
+        let rows = (self.y_max - self.y) as usize;
+        cols + rows * (self.x_max - self.x_min + 1) as usize
+    }
+}
+
+/// Returns an iterator over all the tiles covering a lon/lat bounding box, at a given zoom.
+///
+/// # Arguments
+///
+/// * `west`  - west longitude coordinate, in degrees
+/// * `south` - south latitude coordinate, in degrees
+/// * `east`  - east longitude coordinate, in degrees
+/// * `north` - north latitude coordinate, in degrees
+/// * `zoom`  - zoomlevel of the resulting tiles
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     for (x, y) in smt::bbox_tiles(12.3, 45.4, 12.4, 45.5, 13) {
+///         println!("tile: ({}, {})", x, y);
+///     }
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function is built directly on `lonlat2tile`, and therefore **does not** check on the
+/// *validity* of the data in input; passing a `north` south of `south`, or a `west` east of `east`,
+/// still gets in output an iterator, albeit *meaningless*.
+pub fn bbox_tiles(west: f64, south: f64, east: f64, north: f64, zoom: u8) -> BboxTiles {
+    let (x_min, y_min) = lonlat2tile(west, north, zoom);
+    let (x_max, y_max) = lonlat2tile(east, south, zoom);
+    BboxTiles {
+        x_min,
+        x_max,
+        y_max,
+        x: x_min,
+        y: y_min,
+        done: false,
+    }
+}
+
+/// The radius, in meters, of the sphere used by the Web Mercator (EPSG:3857) projection.
+const EARTH_RADIUS: f64 = 6378137.0;
+
+/// The half-length, in meters, of the Web Mercator square extent (`PI * EARTH_RADIUS`, rounded to the centimeter).
+const ORIGIN_SHIFT: f64 = 20037508.34;
+
+/// Convert lon/lat coordinates to Spherical Mercator (EPSG:3857) meters.
+///
+/// # Arguments
+///
+/// * `lon` - longitude coordinate (W-E), in degrees
+/// * `lat` - latitude  coordinate (N-S), in degrees
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::lonlat2meters(14.016667, 42.683333);
+///     println!("lon 14.016667 E, lat 42.683333 N: {:?}", res);
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function **does not** check on the *validity* of the data in input;
+/// passing non valid coordinates still gets in output a result, albeit *meaningless*.
+pub fn lonlat2meters(lon: f64, lat: f64) -> (f64, f64) {
+    let mx = lon * EARTH_RADIUS * PI / 180f64;
+    let my = EARTH_RADIUS * ( (PI / 4f64 + lat * PI / 360f64).tan() ).ln();
+    (mx, my)
+}
+
+/// Convert Spherical Mercator (EPSG:3857) meters to lon/lat coordinates.
+///
+/// # Arguments
+///
+/// * `mx` - X coordinate (W-E), in Spherical Mercator meters
+/// * `my` - Y coordinate (N-S), in Spherical Mercator meters
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::meters2lonlat(1560438.56, 5280574.37);
+///     println!("{:?}", res);
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function **does not** check on the *validity* of the data in input;
+/// passing meters outside the `[-20037508.34, 20037508.34]` extent still gets in output a
+/// result, albeit *meaningless*.
+pub fn meters2lonlat(mx: f64, my: f64) -> (f64, f64) {
+    let lon = mx / EARTH_RADIUS * 180f64 / PI;
+    let lat = ( (my / EARTH_RADIUS).exp().atan() * 2f64 - PI / 2f64 ).to_degrees();
+    (lon, lat)
+}
+
+/// Returns the Spherical Mercator (EPSG:3857) meters of the NW corner of a tile, at a given zoom.
+///
+/// # Arguments
+///
+/// * `x`  - X tile coordinate
+/// * `y`  - Y tile coordinate
+/// * `zoom` - zoomlevel of the tile
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::tile2meters(4376, 2932, 13);
+///     println!("Tile (4376, 2932) at zoom 13: {:?}", res);
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function **does not** check on the *validity* of the data in input;
+/// passing non valid tiles for the relative zoomlevel still gets in output a result, albeit *meaningless*.
+pub fn tile2meters(x: u32, y: u32, zoom: u8) -> (f64, f64) {
+    let zz: f64 = 2f64.powf(zoom as f64);
+    let res = 2f64 * ORIGIN_SHIFT / zz;
+    let mx = x as f64 * res - ORIGIN_SHIFT;
+    let my = ORIGIN_SHIFT - y as f64 * res;
+    (mx, my)
+}
+
+/// Parses a `z/x/y` style tile path, optionally with a file extension, into a tile.
+///
+/// # Arguments
+///
+/// * `path` - the tile path, e.g. `"10/547/380"` or `"/10/547/380.png"`
+///
+/// # Output
+///
+/// The tile is returned as `Some((x, y, zoom))`, or `None` if `path` is not in the expected format.
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::parse_tile_path("/10/547/380.png");
+///     println!("{:?}", res); // Some((547, 380, 10))
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// Unlike the rest of the crate, which deliberately does not validate its input, this function
+/// returns `None` on malformed input rather than producing a meaningless result.
+pub fn parse_tile_path(path: &str) -> Option<(u32, u32, u8)> {
+    let trimmed = path.trim_matches('/');
+    let mut parts = trimmed.splitn(3, '/');
+    let zoom = parts.next()?.parse::<u8>().ok()?;
+    let x = parts.next()?.parse::<u32>().ok()?;
+    let y_part = parts.next()?;
+    let y_str = match y_part.rfind('.') {
+        Some(i) => &y_part[..i],
+        None => y_part,
+    };
+    let y = y_str.parse::<u32>().ok()?;
+    Some((x, y, zoom))
+}
+
+/// Formats a tile as a `z/x/y` style path, using the given separator and extension.
+///
+/// # Arguments
+///
+/// * `x`    - X tile coordinate
+/// * `y`    - Y tile coordinate
+/// * `zoom` - zoomlevel of the tile
+/// * `sep`  - separator between the `zoom`, `x` and `y` components, e.g. `"/"`
+/// * `ext`  - extension appended to the path, e.g. `".png"`, or `""` for none
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::format_tile(547, 380, 10, "/", ".png");
+///     assert_eq!(res, "10/547/380.png");
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function **does not** check on the *validity* of the data in input;
+/// passing non valid tiles for the relative zoomlevel still gets in output a result, albeit *meaningless*.
+pub fn format_tile(x: u32, y: u32, zoom: u8, sep: &str, ext: &str) -> String {
+    format!("{}{}{}{}{}{}", zoom, sep, x, sep, y, ext)
+}
+
+/// Flips the Y tile coordinate between XYZ (origin top-left) and TMS (origin bottom-left) numbering.
+///
+/// # Arguments
+///
+/// * `y`    - Y tile coordinate, in either XYZ or TMS numbering
+/// * `zoom` - zoomlevel of the tile
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::flip_y(380, 10); // 643
+///     println!("{}", res);
+/// }
+/// ```
+///
+/// # Unexpected Behavior
+///
+/// This function **does not** check on the *validity* of the data in input;
+/// passing a `y` outside `0..2^zoom` still gets in output a result, albeit *meaningless*.
+pub fn flip_y(y: u32, zoom: u8) -> u32 {
+    ((1u32 << zoom) - 1).wrapping_sub(y)
+}
+
+/// Returns the number of tiles along one side of the grid, at a given zoom (`2^zoom`).
+///
+/// # Arguments
+///
+/// * `zoom` - zoomlevel of the grid
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::num_tiles(13); // 8192
+///     println!("{}", res);
+/// }
+/// ```
+pub fn num_tiles(zoom: u8) -> u64 {
+    1u64 << zoom
+}
+
+/// Normalizes a tile whose coordinates may have crossed the antimeridian or exceeded the grid.
+///
+/// The X coordinate is wrapped modulo `2^zoom` (so `-1` becomes the easternmost column and
+/// `2^zoom` becomes column `0`), while the Y coordinate is clamped to `0..=2^zoom - 1`.
+///
+/// # Arguments
+///
+/// * `x`    - X tile coordinate, possibly out of range
+/// * `y`    - Y tile coordinate, possibly out of range
+/// * `zoom` - zoomlevel of the tile
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::normalize_tile(-1, 3, 3); // (7, 3)
+///     println!("{:?}", res);
+/// }
+/// ```
+pub fn normalize_tile(x: i64, y: i64, zoom: u8) -> (u32, u32) {
+    let n = num_tiles(zoom) as i64;
+    let x = ( (x % n) + n ) % n;
+    let y = y.max(0).min(n - 1);
+    (x as u32, y as u32)
+}
+
+/// Returns the 8 tiles surrounding the given tile, wrapping around the antimeridian and
+/// clamping at the poles.
+///
+/// # Arguments
+///
+/// * `x`    - X tile coordinate
+/// * `y`    - Y tile coordinate
+/// * `zoom` - zoomlevel of the tile
+///
+/// # Output
+///
+/// The 8 neighbors are returned starting from the north-west one, going clockwise:
+/// `[nw, n, ne, e, se, s, sw, w]`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let res = smt::tile_neighbors(4376, 2932, 13);
+///     println!("{:?}", res);
+/// }
+/// ```
+pub fn tile_neighbors(x: u32, y: u32, zoom: u8) -> [(u32, u32); 8] {
+    let (x, y) = (x as i64, y as i64);
+    [
+        normalize_tile(x - 1, y - 1, zoom),
+        normalize_tile(x,     y - 1, zoom),
+        normalize_tile(x + 1, y - 1, zoom),
+        normalize_tile(x + 1, y,     zoom),
+        normalize_tile(x + 1, y + 1, zoom),
+        normalize_tile(x,     y + 1, zoom),
+        normalize_tile(x - 1, y + 1, zoom),
+        normalize_tile(x - 1, y,     zoom),
+    ]
+}
+
+/// A validating, zoom-aware tile, as an ergonomic layer over the free functions of this crate.
+///
+/// Unlike the free functions, which deliberately do not validate their input, `Tile::new` checks
+/// that `x` and `y` are within the grid at the given zoomlevel `z`. The fields are public, so a
+/// `Tile` written directly as a struct literal is not checked.
+///
+/// # Examples
+///
+/// ```
+/// extern crate slippy_map_tilenames as smt;
+///
+/// fn main() {
+///     let tile = smt::Tile::new(4376, 2932, 13).unwrap();
+///     println!("{:?}", tile.bbox());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+    pub z: u8,
+}
+
+impl Tile {
+    /// Builds a new `Tile`, validating that `x` and `y` lie within `0..2^z` and that `z` is sane.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X tile coordinate
+    /// * `y` - Y tile coordinate
+    /// * `z` - zoomlevel of the tile
+    ///
+    /// # Output
+    ///
+    /// Returns `None` if `z >= 32`, or if `x` or `y` are outside `0..2^z`.
+    pub fn new(x: u32, y: u32, z: u8) -> Option<Tile> {
+        if z >= 32 {
+            return None;
+        }
+        let n = num_tiles(z);
+        if (x as u64) >= n || (y as u64) >= n {
+            return None;
+        }
+        Some(Tile { x, y, z })
+    }
+
+    /// Returns the tile into which `self` is merged when zooming out to `z - 1`.
+    ///
+    /// Returns `None` at zoom `0`, which has no parent.
+    pub fn parent(&self) -> Option<Tile> {
+        if self.z == 0 {
+            return None;
+        }
+        let (x, y) = zoom_out(self.x, self.y);
+        Tile::new(x, y, self.z - 1)
+    }
+
+    /// Returns the 4 tiles into which `self` is split when zooming in to `z + 1`.
+    ///
+    /// Grafically:
+    ///
+    /// ```text
+    /// +--------+--------+
+    /// | x1, y1 | x2, y1 |
+    /// +--------+--------+
+    /// | x1, y2 | x2, y2 |
+    /// +--------+--------+
+    /// ```
+    pub fn children(&self) -> Option<[Tile; 4]> {
+        let z = self.z.checked_add(1)?;
+        let ((x1, y1), (x2, _), (_, y2), (_, _)) = zoom_in(self.x, self.y);
+        Some([
+            Tile::new(x1, y1, z)?,
+            Tile::new(x2, y1, z)?,
+            Tile::new(x1, y2, z)?,
+            Tile::new(x2, y2, z)?,
+        ])
+    }
+
+    /// Returns the lon/lat coordinates of the center of the tile, in degrees.
+    pub fn center_lonlat(&self) -> (f64, f64) {
+        let (west, south, east, north) = self.bbox();
+        ( (west + east) / 2f64, (south + north) / 2f64 )
+    }
+
+    /// Returns the geographic bounding box of the tile, as `(west, south, east, north)`, in degrees.
+    pub fn bbox(&self) -> (f64, f64, f64, f64) {
+        tile_bbox(self.x, self.y, self.z)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,6 +820,81 @@ mod tests {
         assert_eq!(lonlat2tile(f64::INFINITY, f64::NEG_INFINITY, 0), (0, 0));
     }
     #[test]
+    fn test_tile_bbox_basic() {
+        assert_eq!(tile_bbox(4376, 2932, 13), (12.3046875, 45.42929873257376, 12.3486328125, 45.460130637921));
+        assert_eq!(tile_bbox(0, 0, 0), (-180.0, -85.0511287798066, 180.0, 85.0511287798066));
+    }
+    #[test]
+    fn test_quadkey_basic() {
+        assert_eq!(tile2quadkey(4376, 2932, 13), "1202302231200");
+        assert_eq!(quadkey2tile("1202302231200"), (4376, 2932, 13));
+        assert_eq!(tile2quadkey(0, 0, 0), "");
+        assert_eq!(quadkey2tile(""), (0, 0, 0));
+    }
+    #[test]
+    fn test_bbox_tiles_basic() {
+        let tiles: Vec<(u32, u32)> = bbox_tiles(12.3, 45.4, 12.4, 45.5, 13).collect();
+        assert_eq!(tiles, vec![
+            (4375, 2930), (4376, 2930), (4377, 2930), (4378, 2930),
+            (4375, 2931), (4376, 2931), (4377, 2931), (4378, 2931),
+            (4375, 2932), (4376, 2932), (4377, 2932), (4378, 2932),
+            (4375, 2933), (4376, 2933), (4377, 2933), (4378, 2933),
+        ]);
+        assert_eq!(bbox_tiles(12.3, 45.4, 12.4, 45.5, 13).len(), 16);
+    }
+    #[test]
+    fn test_meters_basic() {
+        assert_eq!(lonlat2meters(14.016667, 42.683333), (1560328.2330588815, 5263895.440498972));
+        let (lon, lat) = meters2lonlat(1560328.2330588815, 5263895.440498972);
+        assert_eq!(format!("{:.6}", lon), "14.016667");
+        assert_eq!(format!("{:.6}", lat), "42.683333");
+        assert_eq!(tile2meters(4376, 2932, 13), (1369751.5466796868, 5694252.858339844));
+    }
+    #[test]
+    fn test_tile_path_basic() {
+        assert_eq!(parse_tile_path("/10/547/380.png"), Some((547, 380, 10)));
+        assert_eq!(parse_tile_path("10/547/380"), Some((547, 380, 10)));
+        assert_eq!(parse_tile_path("not/a/tile"), None);
+        assert_eq!(parse_tile_path("10/547"), None);
+        assert_eq!(format_tile(547, 380, 10, "/", ".png"), "10/547/380.png");
+        assert_eq!(flip_y(380, 10), 643);
+        assert_eq!(flip_y(flip_y(380, 10), 10), 380);
+    }
+    #[test]
+    fn test_normalize_and_neighbors() {
+        assert_eq!(num_tiles(13), 8192);
+        assert_eq!(normalize_tile(-1, 3, 3), (7, 3));
+        assert_eq!(normalize_tile(8, 3, 3), (0, 3));
+        assert_eq!(normalize_tile(3, -1, 3), (3, 0));
+        assert_eq!(normalize_tile(3, 8, 3), (3, 7));
+        assert_eq!(
+            tile_neighbors(0, 0, 3),
+            [(7, 0), (0, 0), (1, 0), (1, 0), (1, 1), (0, 1), (7, 1), (7, 0)]
+        );
+    }
+    #[test]
+    fn test_tile_type_basic() {
+        assert!(Tile::new(4376, 2932, 13).is_some());
+        assert_eq!(Tile::new(8192, 0, 13), None);
+        assert_eq!(Tile::new(0, 0, 32), None);
+
+        let tile = Tile::new(5, 7, 3).unwrap();
+        assert_eq!(tile.parent(), Tile::new(2, 3, 2));
+        assert_eq!(Tile::new(0, 0, 0).unwrap().parent(), None);
+
+        let children = Tile::new(1, 1, 1).unwrap().children().unwrap();
+        assert_eq!(children, [
+            Tile::new(2, 2, 2).unwrap(),
+            Tile::new(3, 2, 2).unwrap(),
+            Tile::new(2, 3, 2).unwrap(),
+            Tile::new(3, 3, 2).unwrap(),
+        ]);
+
+        let tile = Tile::new(4376, 2932, 13).unwrap();
+        assert_eq!(tile.bbox(), tile_bbox(4376, 2932, 13));
+        assert_eq!(tile.center_lonlat(), (12.32666015625, 45.44471468524738));
+    }
+    #[test]
     fn zoom_basics(){
         assert_eq!(zoom_in(1,1), ((2, 2), (3, 2), (2, 3), (3, 3)) );
         assert_eq!(zoom_out(5,7), (2,3));